@@ -0,0 +1,340 @@
+use std::ffi::OsStr;
+
+use clap::builder::{PossibleValue, TypedValueParser};
+use clap::{Arg, ArgAction, ArgGroup, Command};
+use clap_complete::Shell;
+use convert_case::{Case, Pattern};
+use regex::Regex;
+
+/// The cases `ccase` exposes through `--to` and `--from`, paired with the
+/// name the user types on the command line.
+///
+/// Keeping the table here (rather than leaning on `Case`'s `Debug` output)
+/// lets the value parser, the `completions` subcommand, and `--list` all
+/// agree on exactly the same set of names.
+pub fn cases() -> Vec<(&'static str, Case)> {
+    #[cfg_attr(not(feature = "random"), allow(unused_mut))]
+    let mut cases = vec![
+        ("upper", Case::Upper),
+        ("lower", Case::Lower),
+        ("title", Case::Title),
+        ("toggle", Case::Toggle),
+        ("camel", Case::Camel),
+        ("pascal", Case::Pascal),
+        ("snake", Case::Snake),
+        ("constant", Case::UpperSnake),
+        ("kebab", Case::Kebab),
+        ("cobol", Case::UpperKebab),
+        ("train", Case::Train),
+        ("flat", Case::Flat),
+        ("alternating", Case::Alternating),
+    ];
+    #[cfg(feature = "random")]
+    {
+        cases.push(("random", Case::Random));
+        cases.push(("pseudo-random", Case::PseudoRandom));
+    }
+    cases
+}
+
+/// A tiny xorshift64 PRNG used to make `random`/`pseudo-random` output
+/// reproducible: seeded once from `--seed` rather than from the OS per call.
+#[cfg_attr(not(feature = "random"), allow(dead_code))]
+pub struct Xorshift(u64);
+
+#[cfg_attr(not(feature = "random"), allow(dead_code))]
+impl Xorshift {
+    pub fn new(seed: u64) -> Self {
+        // A zero state is a fixed point for xorshift, so nudge it away.
+        Xorshift(if seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { seed })
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    /// Returns a single pseudo-random bit.
+    pub fn flip(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+}
+
+/// The patterns `ccase` exposes through `--pattern`.
+pub fn patterns() -> Vec<(&'static str, Pattern)> {
+    vec![
+        ("lowercase", Pattern::Lowercase),
+        ("uppercase", Pattern::Uppercase),
+        ("capital", Pattern::Capital),
+        ("camel", Pattern::Camel),
+        ("sentence", Pattern::Sentence),
+        ("toggle", Pattern::Toggle),
+        ("alternating", Pattern::Alternating),
+    ]
+}
+
+/// Parses a case name into a [`Case`], matching case-insensitively against
+/// the names in [`cases`].
+#[derive(Clone)]
+struct CaseParser;
+
+impl TypedValueParser for CaseParser {
+    type Value = Case;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        let value = value.to_string_lossy();
+        for (name, case) in cases() {
+            if name.eq_ignore_ascii_case(&value) {
+                return Ok(case);
+            }
+        }
+        Err(invalid_value(cmd, arg, &value))
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(cases().into_iter().map(|(name, _)| PossibleValue::new(name))))
+    }
+}
+
+/// Parses a pattern name into a [`Pattern`], matching case-insensitively
+/// against the names in [`patterns`].
+#[derive(Clone)]
+struct PatternParser;
+
+impl TypedValueParser for PatternParser {
+    type Value = Pattern;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        let value = value.to_string_lossy();
+        for (name, pattern) in patterns() {
+            if name.eq_ignore_ascii_case(&value) {
+                return Ok(pattern);
+            }
+        }
+        Err(invalid_value(cmd, arg, &value))
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(
+            patterns().into_iter().map(|(name, _)| PossibleValue::new(name)),
+        ))
+    }
+}
+
+/// Compiles the supplied pattern into a [`Regex`], surfacing compile errors
+/// through clap rather than panicking at convert time.
+#[derive(Clone)]
+struct RegexParser;
+
+impl TypedValueParser for RegexParser {
+    type Value = Regex;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        let value = value.to_string_lossy();
+        Regex::new(&value).map_err(|e| {
+            let arg = arg.map(ToString::to_string).unwrap_or_else(|| "value".to_string());
+            cmd.clone().error(
+                clap::error::ErrorKind::ValueValidation,
+                format!("Invalid value '{value}' for '{arg}': {e}"),
+            )
+        })
+    }
+}
+
+/// Parses a 1-based field list like `1,3-4` into the set of selected indices.
+#[derive(Clone)]
+struct FieldsParser;
+
+impl TypedValueParser for FieldsParser {
+    type Value = Vec<usize>;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        let value = value.to_string_lossy();
+        let mut fields = Vec::new();
+        for part in value.split(',') {
+            let range = match part.split_once('-') {
+                Some((lo, hi)) => parse_index(lo).zip(parse_index(hi)),
+                None => parse_index(part).map(|n| (n, n)),
+            };
+            match range {
+                Some((lo, hi)) if lo >= 1 && lo <= hi => fields.extend(lo..=hi),
+                _ => return Err(invalid_value(cmd, arg, &value)),
+            }
+        }
+        Ok(fields)
+    }
+}
+
+fn parse_index(s: &str) -> Option<usize> {
+    s.trim().parse::<usize>().ok()
+}
+
+fn invalid_value(cmd: &Command, arg: Option<&Arg>, value: &str) -> clap::Error {
+    let arg = arg.map(ToString::to_string).unwrap_or_else(|| "value".to_string());
+    cmd.clone().error(
+        clap::error::ErrorKind::InvalidValue,
+        format!("Invalid value '{value}' for '{arg}'"),
+    )
+}
+
+pub fn build_app() -> Command {
+    #[allow(unused_mut)]
+    let mut app = Command::new("ccase")
+        .version(env!("CARGO_PKG_VERSION"))
+        .author("Dave Purdum <purdum41@gmail.com>")
+        .about("Convert between string cases.")
+        .arg_required_else_help(true)
+        .subcommand_negates_reqs(true)
+        .group(
+            ArgGroup::new("target")
+                .args(["to", "pattern", "list"])
+                .required(true),
+        )
+        .arg(
+            Arg::new("input")
+                .help("The string to convert.")
+                .action(ArgAction::Append)
+                .value_name("input"),
+        )
+        .arg(
+            Arg::new("to")
+                .short('t')
+                .long("to")
+                .help("Case to convert the input into.")
+                .value_name("case")
+                .value_parser(CaseParser)
+                .conflicts_with_all(["pattern", "delimeter"]),
+        )
+        .arg(
+            Arg::new("from")
+                .short('f')
+                .long("from")
+                .help("Case to parse the input as.")
+                .value_name("case")
+                .value_parser(CaseParser)
+                .conflicts_with("boundaries"),
+        )
+        .arg(
+            Arg::new("boundaries")
+                .short('b')
+                .long("boundaries")
+                .help("String of boundaries to split the input on.")
+                .value_name("string"),
+        )
+        .arg(
+            Arg::new("pattern")
+                .short('p')
+                .long("pattern")
+                .help("Pattern to apply to the words of the input.")
+                .value_name("pattern")
+                .value_parser(PatternParser),
+        )
+        .arg(
+            Arg::new("delimeter")
+                .short('d')
+                .long("delimeter")
+                .help("String to join the words of the input with.")
+                .value_name("string")
+                .requires("pattern"),
+        )
+        .arg(
+            Arg::new("boundary-regex")
+                .long("boundary-regex")
+                .help("Regular expression describing where to split the input into words.")
+                .value_name("pattern")
+                .value_parser(RegexParser)
+                .conflicts_with_all(["from", "boundaries"]),
+        )
+        .arg(
+            Arg::new("detect")
+                .long("detect")
+                .help("Infer the source case of each input instead of using --from.")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["from", "boundaries", "boundary-regex"]),
+        )
+        .arg(
+            Arg::new("verbose")
+                .short('v')
+                .long("verbose")
+                .help("Print the case detected by --detect to stderr.")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("list")
+                .long("list")
+                .help("List supported cases, patterns, or boundaries with an example of each.")
+                .value_name("what")
+                .value_parser(["cases", "patterns", "boundaries"])
+                .exclusive(true),
+        )
+        .arg(
+            Arg::new("null")
+                .short('0')
+                .long("null")
+                .help("Split input and terminate output with NUL bytes instead of newlines.")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("field-delim")
+                .long("field-delim")
+                .help("Separator splitting each input line into fields.")
+                .value_name("string")
+                .default_value("\t"),
+        )
+        .arg(
+            Arg::new("fields")
+                .long("fields")
+                .help("1-based fields to convert, e.g. 1,3-4. Other fields are left untouched.")
+                .value_name("list")
+                .value_parser(FieldsParser),
+        )
+        .subcommand(
+            Command::new("completions")
+                .about("Generate a shell completion script.")
+                .hide(true)
+                .arg(
+                    Arg::new("shell")
+                        .help("The shell to generate completions for.")
+                        .required(true)
+                        .value_name("shell")
+                        .value_parser(clap::value_parser!(Shell)),
+                ),
+        );
+
+    #[cfg(feature = "random")]
+    {
+        app = app.arg(
+            Arg::new("seed")
+                .long("seed")
+                .help("Seed for the random/pseudo-random cases, for reproducible output.")
+                .value_name("number")
+                .value_parser(clap::value_parser!(u64)),
+        );
+    }
+
+    app
+}
@@ -1,7 +1,11 @@
+use ccase::Xorshift;
 use clap::ArgMatches;
+use clap_complete::{generate, Shell};
 use convert_case::{Boundary, Case, Converter, Pattern};
+use regex::Regex;
 use std::env;
 use std::io::{self, Read};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 fn main() {
     let mut app = ccase::build_app();
@@ -12,10 +16,25 @@ fn main() {
             \x1b[32m<input>...\x1b[m",
     );
 
-    let args = get_args_with_stdin();
+    let null = env::args().any(|a| a == "-0" || a == "--null");
+
+    let args = get_args_with_stdin(null);
 
     let matches = app.get_matches_from(args);
 
+    if let Some(sub) = matches.subcommand_matches("completions") {
+        let shell = *sub.get_one::<Shell>("shell").unwrap();
+        let mut cmd = ccase::build_app();
+        let name = cmd.get_name().to_string();
+        generate(shell, &mut cmd, name, &mut io::stdout());
+        return;
+    }
+
+    if let Some(what) = matches.get_one::<String>("list") {
+        list(what);
+        return;
+    }
+
     let inputs = match matches.get_many::<String>("input") {
         None => {
             if atty::isnt(atty::Stream::Stdin) {
@@ -27,16 +46,124 @@ fn main() {
         Some(inputs) => inputs,
     };
 
+    // Seed the PRNG once here and thread it through every conversion so that
+    // `random`/`pseudo-random` output is reproducible across the whole run.
+    #[cfg(feature = "random")]
+    let seed = matches.get_one::<u64>("seed").copied().unwrap_or_else(default_seed);
+    #[cfg(not(feature = "random"))]
+    let seed = default_seed();
+    let mut rng = Xorshift::new(seed);
+
     /*
     inputs.for_each(|input| {
         println!("{:?}", input);
         convert(&matches, input)
     });
     */
-    inputs.for_each(|input| convert(&matches, input));
+    inputs.for_each(|input| convert(&matches, input, &mut rng));
+}
+
+/// Prints each supported case, pattern, or boundary one per line alongside an
+/// example. The case and pattern examples are produced by running the
+/// `Converter` over a fixed sample so the output can never drift from the
+/// `convert_case` version actually in use.
+fn list(what: &str) {
+    const SAMPLE: &str = "my variable name";
+
+    match what {
+        "cases" => {
+            for (name, case) in ccase::cases() {
+                let example = Converter::new().to_case(case).convert(SAMPLE);
+                println!("{name} → {example}");
+            }
+        }
+        "patterns" => {
+            for (name, pattern) in ccase::patterns() {
+                let example = Converter::new().set_pattern(pattern).set_delim(" ").convert(SAMPLE);
+                println!("{name} → {example}");
+            }
+        }
+        "boundaries" => {
+            for (token, sample) in BOUNDARIES {
+                let words = Converter::new()
+                    .set_boundaries(&Boundary::list_from(token))
+                    .set_delim(" ")
+                    .set_pattern(Pattern::Lowercase)
+                    .convert(sample);
+                println!("{token} → splits {sample} into {words}");
+            }
+        }
+        _ => unreachable!("clap restricts --list to known values"),
+    }
+}
+
+/// Boundary tokens accepted by `--boundaries`, each paired with a sample that
+/// the token splits, so the example reflects the real `Boundary::list_from`
+/// behavior rather than a hardcoded description.
+const BOUNDARIES: &[(&str, &str)] = &[
+    ("_", "my_variable_name"),
+    ("-", "my-variable-name"),
+    (" ", "my variable name"),
+    ("aA", "myVariableName"),
+    ("a1", "version2release"),
+    ("1a", "version2release"),
+];
+
+/// Guesses the source case of `input` by inspecting its boundaries: which
+/// separators it uses and how it capitalizes. Returns `None` when the input is
+/// ambiguous (mixed separators, or a single run with no case signal) so the
+/// caller can fall back to the converter's default all-boundary split.
+fn detect_case(input: &str) -> Option<Case> {
+    let underscores = input.matches('_').count() > 0;
+    let hyphens = input.matches('-').count() > 0;
+    let spaces = input.matches(' ').count() > 0;
+
+    let letters: Vec<char> = input.chars().filter(|c| c.is_alphabetic()).collect();
+    let has_upper = letters.iter().any(|c| c.is_uppercase());
+    let has_lower = letters.iter().any(|c| c.is_lowercase());
+    let first_upper = letters.first().is_some_and(|c| c.is_uppercase());
+    let internal_caps = input
+        .chars()
+        .zip(input.chars().skip(1))
+        .any(|(a, b)| a.is_lowercase() && b.is_uppercase());
+
+    match (underscores, hyphens, spaces) {
+        (true, false, false) => Some(if has_upper && !has_lower {
+            Case::UpperSnake
+        } else {
+            Case::Snake
+        }),
+        (false, true, false) => Some(if has_upper && !has_lower {
+            Case::UpperKebab
+        } else {
+            Case::Kebab
+        }),
+        (false, false, true) => Some(if first_upper { Case::Title } else { Case::Lower }),
+        (false, false, false) if internal_caps || (has_upper && has_lower) => {
+            Some(if first_upper { Case::Pascal } else { Case::Camel })
+        }
+        // No separators and a single case, or an ambiguous mix of separators.
+        _ => None,
+    }
+}
+
+/// Looks up the command-line name for a [`Case`], for `--verbose` reporting.
+fn case_name(case: Case) -> String {
+    ccase::cases()
+        .into_iter()
+        .find(|(_, c)| *c == case)
+        .map(|(name, _)| name.to_string())
+        .unwrap_or_else(|| format!("{case:?}"))
+}
+
+fn default_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0)
 }
 
-fn get_args_with_stdin() -> Vec<String> {
+fn get_args_with_stdin(null: bool) -> Vec<String> {
     let mut args: Vec<String> = env::args_os().map(|x| x.into_string().unwrap()).collect();
 
     if atty::isnt(atty::Stream::Stdin) {
@@ -49,8 +176,18 @@ fn get_args_with_stdin() -> Vec<String> {
         let s = String::from_utf8(v).unwrap();
 
         if !s.is_empty() {
-            for word in s.lines() {
-                args.push(word.trim_end().to_string());
+            if null {
+                // NUL-delimited records keep embedded newlines intact, so
+                // split only on the NUL byte and don't trim. A trailing NUL
+                // terminates the last record rather than starting a new one.
+                let s = s.strip_suffix('\0').unwrap_or(&s);
+                for word in s.split('\0') {
+                    args.push(word.to_string());
+                }
+            } else {
+                for word in s.lines() {
+                    args.push(word.trim_end().to_string());
+                }
             }
         }
     }
@@ -58,22 +195,101 @@ fn get_args_with_stdin() -> Vec<String> {
     args
 }
 
-fn convert(matches: &ArgMatches, input: &String) {
+fn convert(matches: &ArgMatches, input: &str, rng: &mut Xorshift) {
+    let terminator = if matches.get_flag("null") { '\0' } else { '\n' };
+
+    let line = if let Some(fields) = matches.get_one::<Vec<usize>>("fields") {
+        // --fields: split the line, convert only the selected columns, and
+        // rejoin with the same separator leaving the rest untouched.
+        let delim = matches.get_one::<String>("field-delim").unwrap();
+        let converted: Vec<String> = input
+            .split(delim.as_str())
+            .enumerate()
+            .map(|(i, field)| {
+                if fields.contains(&(i + 1)) {
+                    convert_field(matches, field, rng)
+                } else {
+                    field.to_string()
+                }
+            })
+            .collect();
+        converted.join(delim)
+    } else {
+        convert_field(matches, input, rng)
+    };
+
+    print!("{line}{terminator}");
+}
+
+#[cfg_attr(not(feature = "random"), allow(unused_variables))]
+fn convert_field(matches: &ArgMatches, input: &str, rng: &mut Xorshift) -> String {
     // check if from or boundaries or none
 
     let mut conv = Converter::new();
 
-    if let Some(&from) = matches.get_one::<Case>("from") {
+    // When --boundary-regex is given we tokenize the input ourselves and hand
+    // the resulting words to the converter via a single space boundary, so an
+    // owned replacement for `input` may be produced here.
+    let mut owned_input = None;
+
+    if matches.get_flag("detect") {
+        // --detect: infer the source case from the input's own boundaries.
+        match detect_case(input) {
+            Some(case) => {
+                conv = conv.from_case(case);
+                if matches.get_flag("verbose") {
+                    eprintln!("detected {} for {:?}", case_name(case), input);
+                }
+            }
+            None if matches.get_flag("verbose") => {
+                eprintln!("detected default split for {input:?}");
+            }
+            None => {}
+        }
+    } else if let Some(&from) = matches.get_one::<Case>("from") {
         // --from
         conv = conv.from_case(from);
     } else if let Some(boundary_str) = matches.get_one::<String>("boundaries") {
         // --boundaries
         let boundaries = Boundary::list_from(boundary_str.as_str());
         conv = conv.set_boundaries(&boundaries);
+    } else if let Some(re) = matches.get_one::<Regex>("boundary-regex") {
+        // --boundary-regex: split the input at every match boundary, keeping
+        // both the text between matches and the matched runs themselves as
+        // words (a zero-width pattern splits purely on position). The
+        // resulting words are fed to the converter via a single space
+        // boundary, so spaces inside any word, including a whitespace match,
+        // act as further breaks and are not kept.
+        let mut words: Vec<&str> = Vec::new();
+        let mut last = 0;
+        for m in re.find_iter(input) {
+            if m.start() > last {
+                words.push(&input[last..m.start()]);
+            }
+            if !m.as_str().is_empty() {
+                words.push(m.as_str());
+            }
+            last = m.end();
+        }
+        if last < input.len() {
+            words.push(&input[last..]);
+        }
+        conv = conv.set_boundaries(&[Boundary::Space]);
+        owned_input = Some(words.join(" "));
     }
 
+    let input = owned_input.as_deref().unwrap_or(input);
+
     if let Some(&to) = matches.get_one::<Case>("to") {
         // --to
+        #[cfg(feature = "random")]
+        if matches!(to, Case::Random | Case::PseudoRandom) {
+            // Randomized cases take their letters from the flat form of the
+            // input and then have their casing driven by our seeded PRNG,
+            // rather than convert_case's OS-seeded randomness.
+            let base = conv.to_case(Case::Flat).convert(input);
+            return random_case(&base, matches!(to, Case::PseudoRandom), rng);
+        }
         conv = conv.to_case(to);
     } else if let Some(&pattern) = matches.get_one::<Pattern>("pattern") {
         // --pattern
@@ -85,7 +301,37 @@ fn convert(matches: &ArgMatches, input: &String) {
         }
     }
 
-    print!("{}", conv.convert(input))
+    conv.convert(input)
+}
+
+/// Randomizes the casing of `s`'s letters using the seeded PRNG. True random
+/// flips each letter independently; pseudo-random alternates case with an
+/// occasional doubled flip so runs of same-case letters appear.
+#[cfg(feature = "random")]
+fn random_case(s: &str, pseudo: bool, rng: &mut Xorshift) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut upper = false;
+    for c in s.chars() {
+        if !c.is_alphabetic() {
+            out.push(c);
+            continue;
+        }
+        let upper = if pseudo {
+            upper = !upper;
+            if rng.next_u64() % 4 == 0 {
+                upper = !upper;
+            }
+            upper
+        } else {
+            rng.flip()
+        };
+        if upper {
+            out.extend(c.to_uppercase());
+        } else {
+            out.extend(c.to_lowercase());
+        }
+    }
+    out
 }
 
 #[cfg(test)]
@@ -247,6 +493,148 @@ mod test {
             .stdout("my_var_name\nanother_multi_word_token\n");
     }
 
+    #[test]
+    fn completions() {
+        // The generated script mentions the binary, and the subcommand
+        // bypasses the normal --to requirement.
+        ccase(&["completions", "bash"])
+            .success()
+            .stdout(contains("ccase"));
+        ccase(&["completions", "zsh"]).success();
+        ccase(&["completions", "fish"]).success();
+    }
+
+    #[test]
+    fn completions_invalid_shell() {
+        ccase(&["completions", "cmd"])
+            .failure()
+            .stderr(contains("invalid value"));
+    }
+
+    #[test]
+    fn detect() {
+        ccase(&["--detect", "-t", "kebab", "my_var_name"])
+            .success()
+            .stdout("my-var-name\n");
+        ccase(&["--detect", "-t", "snake", "myVarName"])
+            .success()
+            .stdout("my_var_name\n");
+        ccase(&["--detect", "-t", "snake", "my-var-name"])
+            .success()
+            .stdout("my_var_name\n");
+    }
+
+    #[test]
+    fn detect_verbose_reports_case() {
+        ccase(&["--detect", "--verbose", "-t", "kebab", "my_var_name"])
+            .success()
+            .stderr(contains("detected snake"));
+    }
+
+    #[test]
+    fn detect_exclusive_with_from() {
+        ccase(&["--detect", "-f", "snake", "-t", "kebab", "my_var"])
+            .failure()
+            .stderr(contains("cannot be used with"));
+    }
+
+    #[test]
+    fn list_cases() {
+        ccase(&["--list", "cases"])
+            .success()
+            .stdout(contains("snake → my_variable_name"))
+            .stdout(contains("camel → myVariableName"));
+    }
+
+    #[test]
+    fn list_patterns() {
+        ccase(&["--list", "patterns"])
+            .success()
+            .stdout(contains("capital → My Variable Name"));
+    }
+
+    #[test]
+    fn list_boundaries() {
+        ccase(&["--list", "boundaries"])
+            .success()
+            .stdout(contains("splits my_variable_name into my variable name"));
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn random_seeded_is_reproducible() {
+        // The same seed produces the same output every run.
+        ccase(&["-t", "random", "--seed", "42", "myVarName"])
+            .success()
+            .stdout("mYvarNAme\n");
+        ccase(&["-t", "random", "--seed", "42", "myVarName"])
+            .success()
+            .stdout("mYvarNAme\n");
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn pseudo_random_seeded_is_reproducible() {
+        ccase(&["-t", "pseudo-random", "--seed", "7", "myVarName"])
+            .success()
+            .stdout("MYvArNamE\n");
+    }
+
+    #[test]
+    fn fields() {
+        // Only the first column is recased, the rest pass through untouched.
+        ccase(&["-t", "snake", "--field-delim", ",", "--fields", "1", "myVar,myVar"])
+            .success()
+            .stdout("my_var,myVar\n");
+        // A comma-separated list selects multiple columns.
+        ccase(&["-t", "snake", "--field-delim", ",", "--fields", "1,3", "aB,cD,eF"])
+            .success()
+            .stdout("a_b,cD,e_f\n");
+    }
+
+    #[test]
+    fn fields_out_of_range_passthrough() {
+        ccase(&["-t", "snake", "--field-delim", ",", "--fields", "5", "aB,cD"])
+            .success()
+            .stdout("aB,cD\n");
+    }
+
+    #[test]
+    fn fields_invalid_list() {
+        ccase(&["-t", "snake", "--fields", "0", "aB"])
+            .failure()
+            .stderr(contains("Invalid value"))
+            .stderr(contains("--fields"));
+    }
+
+    #[test]
+    fn boundary_regex() {
+        ccase(&["-t", "snake", "--boundary-regex", "[0-9]+", "v2Release3x"])
+            .success()
+            .stdout("v_2_release_3_x\n");
+        ccase(&["-t", "kebab", "--boundary-regex", r"\s+", "hello   world"])
+            .success()
+            .stdout("hello-world\n");
+    }
+
+    #[test]
+    fn boundary_regex_exclusive() {
+        ccase(&["-t", "snake", "--boundary-regex", "_", "-f", "kebab", "myVar"])
+            .failure()
+            .stderr(contains("cannot be used with"));
+        ccase(&["-t", "snake", "--boundary-regex", "_", "-b", "-", "myVar"])
+            .failure()
+            .stderr(contains("cannot be used with"));
+    }
+
+    #[test]
+    fn boundary_regex_invalid() {
+        ccase(&["-t", "snake", "--boundary-regex", "[", "myVar"])
+            .failure()
+            .stderr(contains("Invalid value"))
+            .stderr(contains("--boundary-regex"));
+    }
+
     mod stdin {
         use super::*;
 
@@ -283,5 +671,21 @@ mod test {
                 .success()
                 .stdout("MyVarName\nAnotherMultiWordToken\n");
         }
+
+        #[test]
+        fn null_split_and_terminate() {
+            pipe_ccase("myVarName\0anotherToken\0", &["-t", "snake", "--null"])
+                .success()
+                .stdout("my_var_name\0another_token\0");
+        }
+
+        #[test]
+        fn null_preserves_embedded_newlines() {
+            // A token containing a newline survives as one record because
+            // input is split on NUL, not on lines.
+            pipe_ccase("my\nVar\0", &["-0", "-t", "snake"])
+                .success()
+                .stdout("my\nvar\0");
+        }
     }
 }